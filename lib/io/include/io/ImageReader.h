@@ -23,6 +23,7 @@
 #include <optional>
 #include <string>
 #include <type_traits>
+#include <vector>
 
 namespace cxximg {
 
@@ -36,6 +37,11 @@ public:
         ImageMetadata::FileInfo fileInfo;
         JpegDecodingMode jpegDecodingMode = JpegDecodingMode::RGB;
 
+        // Limits above which the image is rejected, unlimited if not set.
+        std::optional<int> maxWidth;
+        std::optional<int> maxHeight;
+        std::optional<int64_t> maxPixelCount;
+
         Options() = default;
 
         explicit Options(const std::optional<ImageMetadata>& metadata) {
@@ -72,6 +78,9 @@ public:
     /// Read the file EXIF metadata, if present.
     virtual std::optional<ExifMetadata> readExif() const { return std::nullopt; }
 
+    /// Read the file embedded ICC profile, if present.
+    virtual std::optional<std::vector<uint8_t>> readIccProfile() const { return std::nullopt; }
+
     /// Read the file metadata and updates the given structure with the result.
     virtual void readMetadata(std::optional<ImageMetadata>& metadata) const {
         std::optional<ExifMetadata> exif = readExif();
@@ -97,7 +106,35 @@ protected:
     const std::string& path() const noexcept { return mPath; }
     const Options& options() const noexcept { return mOptions; }
 
-    void setDescriptor(const Descriptor& descriptor) noexcept { mDescriptor = descriptor; }
+    void setDescriptor(const Descriptor& descriptor) {
+        validateDimensions(descriptor.layout.width, descriptor.layout.height);
+        mDescriptor = descriptor;
+    }
+
+    /// Checks the image dimensions against the reader options. Readers call it on the header values before building
+    /// the layout, so that invalid or oversized files are rejected with an IOError.
+    void validateDimensions(int width, int height) const {
+        using namespace std::string_literals;
+
+        // unsigned header values may wrap to negative when converted to int
+        if (width <= 0 || height <= 0) {
+            throw IOError("Invalid image dimensions "s + std::to_string(width) + "x" + std::to_string(height) + ".");
+        }
+        if (mOptions.maxWidth && width > *mOptions.maxWidth) {
+            throw IOError("Image width "s + std::to_string(width) + " exceeds the maximum of " +
+                          std::to_string(*mOptions.maxWidth) + ".");
+        }
+        if (mOptions.maxHeight && height > *mOptions.maxHeight) {
+            throw IOError("Image height "s + std::to_string(height) + " exceeds the maximum of " +
+                          std::to_string(*mOptions.maxHeight) + ".");
+        }
+
+        const int64_t pixelCount = static_cast<int64_t>(width) * height;
+        if (mOptions.maxPixelCount && pixelCount > *mOptions.maxPixelCount) {
+            throw IOError("Image pixel count "s + std::to_string(pixelCount) + " exceeds the maximum of " +
+                          std::to_string(*mOptions.maxPixelCount) + ".");
+        }
+    }
 
     template <typename T>
     void validateType() const {
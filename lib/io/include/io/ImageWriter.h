@@ -22,6 +22,7 @@
 
 #include <optional>
 #include <string>
+#include <vector>
 
 namespace cxximg {
 
@@ -34,6 +35,7 @@ public:
     struct Options {
         std::optional<FileFormat> fileFormat;
         std::optional<ImageMetadata> metadata;
+        std::optional<std::vector<uint8_t>> iccProfile; ///< ICC profile to embed, only written in PNG and TIFF images
 
         int jpegQuality = 95;
         TiffCompression tiffCompression = TiffCompression::DEFLATE;
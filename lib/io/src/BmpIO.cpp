@@ -60,6 +60,7 @@ BmpReader::BmpReader(const std::string &path, const Options &options) : ImageRea
         throw IOError(MODULE, "Unsupported compression method (only uncompressed is supported)");
     }
 
+    validateDimensions(header->width, std::abs(header->height));
     setDescriptor({LayoutDescriptor::Builder(header->width, std::abs(header->height))
                            .imageLayout(ImageLayout::INTERLEAVED)
                            .pixelType(bppToPixelType(header->bitPerPixel))
@@ -62,6 +62,7 @@ CfaReader::CfaReader(const std::string &path, const Options &options) : ImageRea
 
     const int width = 2 * static_cast<int>(header->uCFABlockWidth);
     const int height = 2 * static_cast<int>(header->uCFABlockHeight);
+    validateDimensions(width, height);
 
     setDescriptor({LayoutDescriptor::Builder(width, height)
                            .pixelType(phaseToPixelType(header->phase))
@@ -82,6 +82,8 @@ DngReader::DngReader(const std::string &path, const Options &options)
     }
 
     const dng_ifd *ifd = mInfo->fIFD[mInfo->fMainIndex];
+    validateDimensions(static_cast<int>(ifd->fImageWidth), static_cast<int>(ifd->fImageLength));
+
     LayoutDescriptor::Builder builder = LayoutDescriptor::Builder(ifd->fImageWidth, ifd->fImageLength);
 
     if (ifd->fSamplesPerPixel == 1) {
@@ -184,6 +186,38 @@ std::optional<ExifMetadata> DngReader::readExif() const {
     if (dngExif->fSoftware.NotEmpty()) {
         exif.software = dngExif->fSoftware.Get();
     }
+    if (dngExif->fArtist.NotEmpty()) {
+        exif.artist = dngExif->fArtist.Get();
+    }
+    if (dngExif->fCopyright.NotEmpty()) {
+        exif.copyright = dngExif->fCopyright.Get();
+    }
+    if (mInfo->fShared->fDNGVersion != 0) {
+        const uint32 version = mInfo->fShared->fDNGVersion;
+        char data[16] = {0};
+        snprintf(data,
+                 sizeof(data),
+                 "%u.%u.%u.%u",
+                 version >> 24,
+                 (version >> 16) & 0xFF,
+                 (version >> 8) & 0xFF,
+                 version & 0xFF);
+        exif.dngVersion = data;
+    }
+    if (mNegative->ModelName().NotEmpty()) {
+        exif.uniqueCameraModel = mNegative->ModelName().Get();
+    }
+    if (mNegative->OriginalRawFileName().NotEmpty()) {
+        exif.originalRawFileName = mNegative->OriginalRawFileName().Get();
+    }
+    if (mNegative->OriginalRawFileDigest().IsValid()) {
+        const dng_fingerprint &digest = mNegative->OriginalRawFileDigest();
+        char data[2 * dng_fingerprint::kDNGFingerprintSize + 1] = {0};
+        for (uint32 i = 0; i < dng_fingerprint::kDNGFingerprintSize; ++i) {
+            snprintf(data + 2 * i, 3, "%02x", digest.data[i]);
+        }
+        exif.originalRawFileDigest = data;
+    }
     if (dngExif->fExposureTime.IsValid()) {
         exif.exposureTime = {dngExif->fExposureTime.n, dngExif->fExposureTime.d};
     }
@@ -193,6 +227,12 @@ std::optional<ExifMetadata> DngReader::readExif() const {
     if (dngExif->fISOSpeedRatings[0] != 0) {
         exif.isoSpeedRatings = dngExif->fISOSpeedRatings[0];
     }
+    if (dngExif->fSensitivityType != 0) {
+        exif.sensitivityType = dngExif->fSensitivityType;
+    }
+    if (dngExif->fRecommendedExposureIndex != 0) {
+        exif.recommendedExposureIndex = dngExif->fRecommendedExposureIndex;
+    }
     if (dngExif->fDateTimeOriginal.IsValid()) {
         const dng_date_time &dateTime = dngExif->fDateTimeOriginal.DateTime();
         char data[20] = {0};
@@ -207,33 +247,99 @@ std::optional<ExifMetadata> DngReader::readExif() const {
                  dateTime.fSecond);
         exif.dateTimeOriginal = data;
     }
+    if (dngExif->fShutterSpeedValue.IsValid()) {
+        exif.shutterSpeedValue = {dngExif->fShutterSpeedValue.n, dngExif->fShutterSpeedValue.d};
+    }
+    if (dngExif->fApertureValue.IsValid()) {
+        exif.apertureValue = {dngExif->fApertureValue.n, dngExif->fApertureValue.d};
+    }
+    if (dngExif->fMaxApertureValue.IsValid()) {
+        exif.maxApertureValue = {dngExif->fMaxApertureValue.n, dngExif->fMaxApertureValue.d};
+    }
+    if (dngExif->fSubjectDistance.IsValid()) {
+        exif.subjectDistance = {dngExif->fSubjectDistance.n, dngExif->fSubjectDistance.d};
+    }
+    if (dngExif->fLightSource != 0xFFFFFFFF) {
+        exif.lightSource = dngExif->fLightSource;
+    }
+    if (dngExif->fFlash != 0xFFFFFFFF) {
+        exif.flash = dngExif->fFlash;
+    }
     if (dngExif->fFocalLength.IsValid()) {
         exif.focalLength = {dngExif->fFocalLength.n, dngExif->fFocalLength.d};
     }
+    if (dngExif->fUserComment.NotEmpty()) {
+        exif.userComment = dngExif->fUserComment.Get();
+    }
+    if (dngExif->fExposureIndex.IsValid()) {
+        exif.exposureIndex = {dngExif->fExposureIndex.n, dngExif->fExposureIndex.d};
+    }
+    if (dngExif->fExposureMode != 0xFFFFFFFF) {
+        exif.exposureMode = dngExif->fExposureMode;
+    }
+    if (dngExif->fWhiteBalance != 0xFFFFFFFF) {
+        exif.whiteBalance = dngExif->fWhiteBalance;
+    }
+    if (dngExif->fDigitalZoomRatio.IsValid()) {
+        exif.digitalZoomRatio = {dngExif->fDigitalZoomRatio.n, dngExif->fDigitalZoomRatio.d};
+    }
     if (dngExif->fFocalLengthIn35mmFilm != 0) {
         exif.focalLengthIn35mmFilm = dngExif->fFocalLengthIn35mmFilm;
     }
+    if (dngExif->fSceneCaptureType != 0xFFFFFFFF) {
+        exif.sceneCaptureType = dngExif->fSceneCaptureType;
+    }
+    if (dngExif->fSubjectDistanceRange != 0xFFFFFFFF) {
+        exif.subjectDistanceRange = dngExif->fSubjectDistanceRange;
+    }
 
     return exif;
 }
 
+std::optional<std::vector<uint8_t>> DngReader::readIccProfile() const {
+    const dng_shared &shared = *mInfo->fShared;
+    if (shared.fAsShotICCProfileCount == 0) {
+        return std::nullopt;
+    }
+
+    try {
+        // do not trust the size claimed by the file before allocating the profile
+        if (shared.fAsShotICCProfileOffset > mStream->Length() ||
+            shared.fAsShotICCProfileCount > mStream->Length() - shared.fAsShotICCProfileOffset) {
+            LOG_S(WARNING) << "Ignoring AsShotICCProfile, which extends past the end of the file";
+            return std::nullopt;
+        }
+
+        std::vector<uint8_t> profile(shared.fAsShotICCProfileCount);
+        mStream->SetReadPosition(shared.fAsShotICCProfileOffset);
+        mStream->Get(profile.data(), shared.fAsShotICCProfileCount);
+
+        return profile;
+    } catch (const dng_exception &except) {
+        throw IOError(MODULE, "Reading failed with error code " + std::to_string(except.ErrorCode()));
+    }
+}
+
 void DngReader::readMetadata(std::optional<ImageMetadata> &metadata) const {
     ImageReader::readMetadata(metadata);
     if (!metadata) {
         metadata = ImageMetadata{};
     }
 
+    // Tells mosaic (bayer) DNGs apart from linear (RGB) ones
+    metadata->fileInfo.pixelType = layoutDescriptor().pixelType;
+
     metadata->shootingParams.ispGain = std::exp2(mNegative->BaselineExposure());
 
+    dng_camera_profile_id profileId; // default profile ID
+    dng_camera_profile profile;
+    const bool haveProfile = mNegative->GetProfileByID(profileId, profile);
+
     if (mNegative->HasCameraNeutral()) {
         const dng_vector &neutral = mNegative->CameraNeutral();
         metadata->cameraControls.whiteBalance = {static_cast<float>(1.0 / neutral[0]),
                                                  static_cast<float>(1.0 / neutral[2])};
 
-        dng_camera_profile_id profileId; // default profile ID
-        dng_camera_profile profile;
-        const bool haveProfile = mNegative->GetProfileByID(profileId, profile);
-
         if (haveProfile && profile.IsValid(mNegative->ColorChannels()) && !profile.ColorMatrix1().IsIdentity()) {
             // Compute camera to sRGB color matrix
             AutoPtr<dng_color_spec> spec(mNegative->MakeColorSpec(profileId));
@@ -249,15 +355,50 @@ void DngReader::readMetadata(std::optional<ImageMetadata> &metadata) const {
         }
     }
 
+    if (haveProfile && profile.ToneCurve().IsValid() && !profile.ToneCurve().IsNull()) {
+        metadata->calibrationData.toneCurve = std::vector<Point2>{};
+        for (const dng_point_real64 &point : profile.ToneCurve().fCoord) {
+            metadata->calibrationData.toneCurve->push_back({static_cast<float>(point.h), static_cast<float>(point.v)});
+        }
+    }
+
     const dng_linearization_info *linearizationInfo = mNegative->GetLinearizationInfo();
     if (linearizationInfo) {
+        const dng_memory_block *blackDeltaH = linearizationInfo->fBlackDeltaH.Get();
+        const dng_memory_block *blackDeltaV = linearizationInfo->fBlackDeltaV.Get();
+
+        // MaxBlackLevel() already includes the largest deltas, so report the base level when they are exposed
+        const real64 blackLevel = (blackDeltaH || blackDeltaV) ? linearizationInfo->fBlackLevel[0][0][0]
+                                                               : linearizationInfo->MaxBlackLevel(0);
+
         if (mNegative->IsFloatingPoint()) {
-            metadata->calibrationData.blackLevel = static_cast<float>(linearizationInfo->MaxBlackLevel(0));
+            metadata->calibrationData.blackLevel = static_cast<float>(blackLevel);
             metadata->calibrationData.whiteLevel = static_cast<float>(linearizationInfo->fWhiteLevel[0]);
         } else {
-            metadata->calibrationData.blackLevel = static_cast<int>(std::lround(linearizationInfo->MaxBlackLevel(0)));
+            metadata->calibrationData.blackLevel = static_cast<int>(std::lround(blackLevel));
             metadata->calibrationData.whiteLevel = static_cast<int>(std::lround(linearizationInfo->fWhiteLevel[0]));
         }
+
+        // DNG deltas only cover the active area, expand them to the whole image with zeros
+        const dng_rect &activeArea = linearizationInfo->fActiveArea;
+        const auto readBlackDeltas = [](const dng_memory_block *deltas, int32 offset, int size) {
+            const real64 *buffer = deltas->Buffer_real64();
+            const auto count = static_cast<int32>(deltas->LogicalSize() / sizeof(real64));
+
+            std::vector<float> expanded(size, 0.0f);
+            for (int32 i = 0; i < count && offset + i < size; ++i) {
+                expanded[offset + i] = static_cast<float>(buffer[i]);
+            }
+            return expanded;
+        };
+        if (blackDeltaH) {
+            metadata->calibrationData.blackLevelDeltaH =
+                    readBlackDeltas(blackDeltaH, activeArea.l, layoutDescriptor().width);
+        }
+        if (blackDeltaV) {
+            metadata->calibrationData.blackLevelDeltaV =
+                    readBlackDeltas(blackDeltaV, activeArea.t, layoutDescriptor().height);
+        }
     }
 
     std::unordered_map<Bayer, const dng_gain_map *> gainMaps;
@@ -356,6 +497,12 @@ static void populateExif(dng_exif *dngExif, const ExifMetadata &exif) {
     if (exif.software) {
         dngExif->fSoftware.Set(exif.software->c_str());
     }
+    if (exif.artist) {
+        dngExif->fArtist.Set(exif.artist->c_str());
+    }
+    if (exif.copyright) {
+        dngExif->fCopyright.Set(exif.copyright->c_str());
+    }
     if (exif.exposureTime) {
         dngExif->fExposureTime = {exif.exposureTime->numerator, exif.exposureTime->denominator};
     }
@@ -365,18 +512,63 @@ static void populateExif(dng_exif *dngExif, const ExifMetadata &exif) {
     if (exif.isoSpeedRatings) {
         dngExif->fISOSpeedRatings[0] = *exif.isoSpeedRatings;
     }
+    if (exif.sensitivityType) {
+        dngExif->fSensitivityType = *exif.sensitivityType;
+    }
+    if (exif.recommendedExposureIndex) {
+        dngExif->fRecommendedExposureIndex = *exif.recommendedExposureIndex;
+    }
     if (exif.dateTimeOriginal) {
         dng_date_time dateTime;
         if (dateTime.Parse(exif.dateTimeOriginal->c_str())) {
             dngExif->fDateTimeOriginal.SetDateTime(dateTime);
         }
     }
+    if (exif.shutterSpeedValue) {
+        dngExif->fShutterSpeedValue = {exif.shutterSpeedValue->numerator, exif.shutterSpeedValue->denominator};
+    }
+    if (exif.apertureValue) {
+        dngExif->fApertureValue = {exif.apertureValue->numerator, exif.apertureValue->denominator};
+    }
+    if (exif.maxApertureValue) {
+        dngExif->fMaxApertureValue = {exif.maxApertureValue->numerator, exif.maxApertureValue->denominator};
+    }
+    if (exif.subjectDistance) {
+        dngExif->fSubjectDistance = {exif.subjectDistance->numerator, exif.subjectDistance->denominator};
+    }
+    if (exif.lightSource) {
+        dngExif->fLightSource = *exif.lightSource;
+    }
+    if (exif.flash) {
+        dngExif->fFlash = *exif.flash;
+    }
     if (exif.focalLength) {
         dngExif->fFocalLength = {exif.focalLength->numerator, exif.focalLength->denominator};
     }
+    if (exif.userComment) {
+        dngExif->fUserComment.Set(exif.userComment->c_str());
+    }
+    if (exif.exposureIndex) {
+        dngExif->fExposureIndex = {exif.exposureIndex->numerator, exif.exposureIndex->denominator};
+    }
+    if (exif.exposureMode) {
+        dngExif->fExposureMode = *exif.exposureMode;
+    }
+    if (exif.whiteBalance) {
+        dngExif->fWhiteBalance = *exif.whiteBalance;
+    }
+    if (exif.digitalZoomRatio) {
+        dngExif->fDigitalZoomRatio = {exif.digitalZoomRatio->numerator, exif.digitalZoomRatio->denominator};
+    }
     if (exif.focalLengthIn35mmFilm) {
         dngExif->fFocalLengthIn35mmFilm = *exif.focalLengthIn35mmFilm;
     }
+    if (exif.sceneCaptureType) {
+        dngExif->fSceneCaptureType = *exif.sceneCaptureType;
+    }
+    if (exif.subjectDistanceRange) {
+        dngExif->fSubjectDistanceRange = *exif.subjectDistanceRange;
+    }
 }
 
 void DngWriter::write(const Image16u &image) const {
@@ -490,6 +682,13 @@ void DngWriter::writeImpl(const Image<T> &image) const {
                 profile->SetColorMatrix1(colorMatrix);
             }
 
+            if (metadata.exifMetadata.uniqueCameraModel) {
+                negative->SetModelName(metadata.exifMetadata.uniqueCameraModel->c_str());
+            }
+            if (metadata.exifMetadata.originalRawFileName) {
+                negative->SetOriginalRawFileName(metadata.exifMetadata.originalRawFileName->c_str());
+            }
+
             populateExif(negative->GetExif(), metadata.exifMetadata);
         }
 
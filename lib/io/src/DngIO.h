@@ -43,6 +43,7 @@ public:
     Imagef readf() override;
 
     std::optional<ExifMetadata> readExif() const override;
+    std::optional<std::vector<uint8_t>> readIccProfile() const override;
     void readMetadata(std::optional<ImageMetadata> &metadata) const override;
 
 private:
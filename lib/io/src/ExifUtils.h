@@ -0,0 +1,58 @@
+// Copyright 2023 Emmanuel Chaboud
+//
+/// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#pragma once
+
+#include <cstdint>
+#include <cstring>
+#include <optional>
+#include <string>
+
+namespace cxximg {
+
+namespace detail {
+
+/// Size of the character code that prefixes the EXIF UserComment value.
+static constexpr size_t USER_COMMENT_CODE_SIZE = 8;
+
+/// Decodes an EXIF UserComment value.
+///
+/// Only the ASCII and undefined character codes are supported, other encodings return an empty optional.
+inline std::optional<std::string> decodeUserComment(const uint8_t *data, size_t size) {
+    static constexpr char ASCII_CODE[USER_COMMENT_CODE_SIZE] = {'A', 'S', 'C', 'I', 'I', 0, 0, 0};
+    static constexpr char UNDEFINED_CODE[USER_COMMENT_CODE_SIZE] = {0, 0, 0, 0, 0, 0, 0, 0};
+
+    if (size < USER_COMMENT_CODE_SIZE) {
+        return std::nullopt;
+    }
+    if (std::memcmp(data, ASCII_CODE, USER_COMMENT_CODE_SIZE) != 0 &&
+        std::memcmp(data, UNDEFINED_CODE, USER_COMMENT_CODE_SIZE) != 0) {
+        return std::nullopt;
+    }
+
+    std::string comment(reinterpret_cast<const char *>(data) + USER_COMMENT_CODE_SIZE, size - USER_COMMENT_CODE_SIZE);
+
+    // Cameras usually pad the comment with null characters or spaces
+    comment.erase(comment.find_last_not_of(std::string(" \0", 2)) + 1);
+    return comment;
+}
+
+/// Encodes an EXIF UserComment value, using the ASCII character code.
+inline std::string encodeUserComment(const std::string &comment) {
+    return std::string("ASCII\0\0\0", USER_COMMENT_CODE_SIZE) + comment;
+}
+
+} // namespace detail
+
+} // namespace cxximg
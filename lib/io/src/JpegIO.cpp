@@ -13,6 +13,8 @@
 // limitations under the License.
 
 #include "JpegIO.h"
+#include "ExifUtils.h"
+#include "JpegUtils.h"
 
 #include <turbojpeg.h>
 #include <loguru.hpp>
@@ -30,6 +32,13 @@ namespace cxximg {
 
 static const std::string MODULE = "JPEG";
 
+#ifdef HAVE_EXIF
+// EXIF 2.3 tags, which libexif only names since version 0.6.22. Older versions also drop them at load, unless the
+// IGNORE_UNKNOWN_TAGS option is unset as in JpegReader::readExif().
+static constexpr ExifTag SENSITIVITY_TYPE_TAG = static_cast<ExifTag>(0x8830);
+static constexpr ExifTag RECOMMENDED_EXPOSURE_INDEX_TAG = static_cast<ExifTag>(0x8832);
+#endif
+
 void JpegDeleter::operator()(void *handle) const {
     tjDestroy(handle);
 }
@@ -71,6 +80,8 @@ JpegReader::JpegReader(const std::string &path, const Options &options) : ImageR
         throw IOError(MODULE, "Failed to decompress header: "s + tjGetErrorStr2(mHandle.get()));
     }
 
+    validateDimensions(width, height);
+
     LayoutDescriptor::Builder builder = LayoutDescriptor::Builder(width, height).pixelPrecision(8);
 
     if (jpegSubsamp == TJSAMP_GRAY) {
@@ -147,11 +158,17 @@ Image8u JpegReader::read8u() {
 
 #ifdef HAVE_EXIF
 std::optional<ExifMetadata> JpegReader::readExif() const {
-    ExifData *data = exif_data_new_from_data(mHeaderData.data(), mHeaderData.size());
+    ExifData *data = exif_data_new();
     if (data == nullptr) {
         return std::nullopt;
     }
 
+    // By default, libexif drops at load the tags it does not record for the image IFDs, such as DocumentName, as well
+    // as the tags unknown to older versions, such as SensitivityType.
+    exif_data_unset_option(data, EXIF_DATA_OPTION_IGNORE_UNKNOWN_TAGS);
+    exif_data_unset_option(data, EXIF_DATA_OPTION_FOLLOW_SPECIFICATION);
+    exif_data_load_data(data, mHeaderData.data(), mHeaderData.size());
+
     ExifMetadata exif;
 
     ExifContent *ifd0 = data->ifd[EXIF_IFD_0];
@@ -168,6 +185,9 @@ std::optional<ExifMetadata> JpegReader::readExif() const {
                     case EXIF_TAG_IMAGE_LENGTH:
                         exif->imageHeight = exif_get_short(entry->data, byteOrder);
                         break;
+                    case EXIF_TAG_DOCUMENT_NAME:
+                        exif->documentName = std::string(reinterpret_cast<const char *>(entry->data), entry->size);
+                        break;
                     case EXIF_TAG_IMAGE_DESCRIPTION:
                         exif->imageDescription = std::string(reinterpret_cast<const char *>(entry->data), entry->size);
                         break;
@@ -183,6 +203,12 @@ std::optional<ExifMetadata> JpegReader::readExif() const {
                     case EXIF_TAG_SOFTWARE:
                         exif->software = std::string(reinterpret_cast<const char *>(entry->data), entry->size);
                         break;
+                    case EXIF_TAG_ARTIST:
+                        exif->artist = std::string(reinterpret_cast<const char *>(entry->data), entry->size);
+                        break;
+                    case EXIF_TAG_COPYRIGHT:
+                        exif->copyright = std::string(reinterpret_cast<const char *>(entry->data), entry->size);
+                        break;
                     default:
                         break;
                 }
@@ -196,6 +222,7 @@ std::optional<ExifMetadata> JpegReader::readExif() const {
                 const ExifByteOrder byteOrder = exif_data_get_byte_order(entry->parent->parent);
                 auto *exif = static_cast<ExifMetadata *>(userData);
                 ExifRational rational;
+                ExifSRational srational;
 
                 switch (entry->tag) {
                     case EXIF_TAG_EXPOSURE_TIME:
@@ -212,14 +239,69 @@ std::optional<ExifMetadata> JpegReader::readExif() const {
                     case EXIF_TAG_DATE_TIME_ORIGINAL:
                         exif->dateTimeOriginal = std::string(reinterpret_cast<const char *>(entry->data), entry->size);
                         break;
+                    case EXIF_TAG_SHUTTER_SPEED_VALUE:
+                        srational = exif_get_srational(entry->data, byteOrder);
+                        exif->shutterSpeedValue = {srational.numerator, srational.denominator};
+                        break;
+                    case EXIF_TAG_APERTURE_VALUE:
+                        rational = exif_get_rational(entry->data, byteOrder);
+                        exif->apertureValue = {rational.numerator, rational.denominator};
+                        break;
+                    case EXIF_TAG_MAX_APERTURE_VALUE:
+                        rational = exif_get_rational(entry->data, byteOrder);
+                        exif->maxApertureValue = {rational.numerator, rational.denominator};
+                        break;
+                    case EXIF_TAG_SUBJECT_DISTANCE:
+                        rational = exif_get_rational(entry->data, byteOrder);
+                        exif->subjectDistance = {rational.numerator, rational.denominator};
+                        break;
+                    case EXIF_TAG_LIGHT_SOURCE:
+                        exif->lightSource = exif_get_short(entry->data, byteOrder);
+                        break;
+                    case EXIF_TAG_FLASH:
+                        exif->flash = exif_get_short(entry->data, byteOrder);
+                        break;
                     case EXIF_TAG_FOCAL_LENGTH:
                         rational = exif_get_rational(entry->data, byteOrder);
                         exif->focalLength = {rational.numerator, rational.denominator};
                         break;
+                    case EXIF_TAG_USER_COMMENT:
+                        exif->userComment = detail::decodeUserComment(entry->data, entry->size);
+                        break;
+                    case EXIF_TAG_FLASH_ENERGY:
+                        rational = exif_get_rational(entry->data, byteOrder);
+                        exif->flashEnergy = {rational.numerator, rational.denominator};
+                        break;
+                    case EXIF_TAG_EXPOSURE_INDEX:
+                        rational = exif_get_rational(entry->data, byteOrder);
+                        exif->exposureIndex = {rational.numerator, rational.denominator};
+                        break;
+                    case EXIF_TAG_EXPOSURE_MODE:
+                        exif->exposureMode = exif_get_short(entry->data, byteOrder);
+                        break;
+                    case EXIF_TAG_WHITE_BALANCE:
+                        exif->whiteBalance = exif_get_short(entry->data, byteOrder);
+                        break;
+                    case EXIF_TAG_DIGITAL_ZOOM_RATIO:
+                        rational = exif_get_rational(entry->data, byteOrder);
+                        exif->digitalZoomRatio = {rational.numerator, rational.denominator};
+                        break;
                     case EXIF_TAG_FOCAL_LENGTH_IN_35MM_FILM:
                         exif->focalLengthIn35mmFilm = exif_get_short(entry->data, byteOrder);
                         break;
+                    case EXIF_TAG_SCENE_CAPTURE_TYPE:
+                        exif->sceneCaptureType = exif_get_short(entry->data, byteOrder);
+                        break;
+                    case EXIF_TAG_SUBJECT_DISTANCE_RANGE:
+                        exif->subjectDistanceRange = exif_get_short(entry->data, byteOrder);
+                        break;
                     default:
+                        // not in the switch, as these tags may not be part of the ExifTag enum
+                        if (entry->tag == SENSITIVITY_TYPE_TAG) {
+                            exif->sensitivityType = exif_get_short(entry->data, byteOrder);
+                        } else if (entry->tag == RECOMMENDED_EXPOSURE_INDEX_TAG) {
+                            exif->recommendedExposureIndex = exif_get_long(entry->data, byteOrder);
+                        }
                         break;
                 }
             },
@@ -231,6 +313,10 @@ std::optional<ExifMetadata> JpegReader::readExif() const {
 }
 #endif
 
+std::optional<std::vector<uint8_t>> JpegReader::readIccProfile() const {
+    return detail::extractJpegIccProfile(mHeaderData.data(), mHeaderData.size());
+}
+
 #ifdef HAVE_EXIF
 static ExifEntry *addExifEntry(ExifContent *ifd, ExifTag tag) {
     ExifEntry *entry = exif_content_get_entry(ifd, tag);
@@ -246,6 +332,18 @@ static ExifEntry *addExifEntry(ExifContent *ifd, ExifTag tag) {
     return entry;
 }
 
+// exif_entry_initialize() leaves the entry empty for the tags it does not know.
+static void exifAllocate(ExifMem *mem, ExifEntry *entry, ExifFormat format) {
+    if (entry->data) {
+        return;
+    }
+
+    entry->format = format;
+    entry->components = 1;
+    entry->size = exif_format_get_size(entry->format) * entry->components;
+    entry->data = reinterpret_cast<unsigned char *>(exif_mem_alloc(mem, entry->size));
+}
+
 static void exifSetString(ExifMem *mem, ExifEntry *entry, const std::string &str) {
     if (entry->data) {
         exif_mem_free(mem, entry->data);
@@ -289,6 +387,11 @@ static void populateExif(ExifMem *mem, ExifData *data, ExifMetadata exif) {
         entry = addExifEntry(ifd0, EXIF_TAG_IMAGE_LENGTH);
         exif_set_short(entry->data, FILE_BYTE_ORDER, *exif.imageHeight);
     }
+    if (exif.documentName) {
+        entry = addExifEntry(ifd0, EXIF_TAG_DOCUMENT_NAME);
+        exifAllocate(mem, entry, EXIF_FORMAT_ASCII);
+        exifSetString(mem, entry, *exif.documentName);
+    }
     if (exif.imageDescription) {
         entry = addExifEntry(ifd0, EXIF_TAG_IMAGE_DESCRIPTION);
         exifSetString(mem, entry, *exif.imageDescription);
@@ -309,6 +412,14 @@ static void populateExif(ExifMem *mem, ExifData *data, ExifMetadata exif) {
         entry = addExifEntry(ifd0, EXIF_TAG_SOFTWARE);
         exifSetString(mem, entry, *exif.software);
     }
+    if (exif.artist) {
+        entry = addExifEntry(ifd0, EXIF_TAG_ARTIST);
+        exifSetString(mem, entry, *exif.artist);
+    }
+    if (exif.copyright) {
+        entry = addExifEntry(ifd0, EXIF_TAG_COPYRIGHT);
+        exifSetString(mem, entry, *exif.copyright);
+    }
     if (exif.exposureTime) {
         entry = addExifEntry(ifdExif, EXIF_TAG_EXPOSURE_TIME);
         exif_set_rational(entry->data, FILE_BYTE_ORDER, {exif.exposureTime->numerator, exif.exposureTime->denominator});
@@ -321,18 +432,96 @@ static void populateExif(ExifMem *mem, ExifData *data, ExifMetadata exif) {
         entry = addExifEntry(ifdExif, EXIF_TAG_ISO_SPEED_RATINGS);
         exif_set_short(entry->data, FILE_BYTE_ORDER, *exif.isoSpeedRatings);
     }
+    if (exif.sensitivityType) {
+        entry = addExifEntry(ifdExif, SENSITIVITY_TYPE_TAG);
+        exifAllocate(mem, entry, EXIF_FORMAT_SHORT);
+        exif_set_short(entry->data, FILE_BYTE_ORDER, *exif.sensitivityType);
+    }
+    if (exif.recommendedExposureIndex) {
+        entry = addExifEntry(ifdExif, RECOMMENDED_EXPOSURE_INDEX_TAG);
+        exifAllocate(mem, entry, EXIF_FORMAT_LONG);
+        exif_set_long(entry->data, FILE_BYTE_ORDER, *exif.recommendedExposureIndex);
+    }
     if (exif.dateTimeOriginal) {
         entry = addExifEntry(ifdExif, EXIF_TAG_DATE_TIME_ORIGINAL);
         exifSetString(mem, entry, *exif.dateTimeOriginal);
     }
+    if (exif.shutterSpeedValue) {
+        entry = addExifEntry(ifdExif, EXIF_TAG_SHUTTER_SPEED_VALUE);
+        exif_set_srational(entry->data,
+                           FILE_BYTE_ORDER,
+                           {exif.shutterSpeedValue->numerator, exif.shutterSpeedValue->denominator});
+    }
+    if (exif.apertureValue) {
+        entry = addExifEntry(ifdExif, EXIF_TAG_APERTURE_VALUE);
+        exif_set_rational(entry->data,
+                          FILE_BYTE_ORDER,
+                          {exif.apertureValue->numerator, exif.apertureValue->denominator});
+    }
+    if (exif.maxApertureValue) {
+        entry = addExifEntry(ifdExif, EXIF_TAG_MAX_APERTURE_VALUE);
+        exif_set_rational(entry->data,
+                          FILE_BYTE_ORDER,
+                          {exif.maxApertureValue->numerator, exif.maxApertureValue->denominator});
+    }
+    if (exif.subjectDistance) {
+        entry = addExifEntry(ifdExif, EXIF_TAG_SUBJECT_DISTANCE);
+        exif_set_rational(entry->data,
+                          FILE_BYTE_ORDER,
+                          {exif.subjectDistance->numerator, exif.subjectDistance->denominator});
+    }
+    if (exif.lightSource) {
+        entry = addExifEntry(ifdExif, EXIF_TAG_LIGHT_SOURCE);
+        exif_set_short(entry->data, FILE_BYTE_ORDER, *exif.lightSource);
+    }
+    if (exif.flash) {
+        entry = addExifEntry(ifdExif, EXIF_TAG_FLASH);
+        exif_set_short(entry->data, FILE_BYTE_ORDER, *exif.flash);
+    }
     if (exif.focalLength) {
         entry = addExifEntry(ifdExif, EXIF_TAG_FOCAL_LENGTH);
         exif_set_rational(entry->data, FILE_BYTE_ORDER, {exif.focalLength->numerator, exif.focalLength->denominator});
     }
+    if (exif.userComment) {
+        entry = addExifEntry(ifdExif, EXIF_TAG_USER_COMMENT);
+        exifSetString(mem, entry, detail::encodeUserComment(*exif.userComment));
+    }
+    if (exif.flashEnergy) {
+        entry = addExifEntry(ifdExif, EXIF_TAG_FLASH_ENERGY);
+        exif_set_rational(entry->data, FILE_BYTE_ORDER, {exif.flashEnergy->numerator, exif.flashEnergy->denominator});
+    }
+    if (exif.exposureIndex) {
+        entry = addExifEntry(ifdExif, EXIF_TAG_EXPOSURE_INDEX);
+        exif_set_rational(entry->data,
+                          FILE_BYTE_ORDER,
+                          {exif.exposureIndex->numerator, exif.exposureIndex->denominator});
+    }
+    if (exif.exposureMode) {
+        entry = addExifEntry(ifdExif, EXIF_TAG_EXPOSURE_MODE);
+        exif_set_short(entry->data, FILE_BYTE_ORDER, *exif.exposureMode);
+    }
+    if (exif.whiteBalance) {
+        entry = addExifEntry(ifdExif, EXIF_TAG_WHITE_BALANCE);
+        exif_set_short(entry->data, FILE_BYTE_ORDER, *exif.whiteBalance);
+    }
+    if (exif.digitalZoomRatio) {
+        entry = addExifEntry(ifdExif, EXIF_TAG_DIGITAL_ZOOM_RATIO);
+        exif_set_rational(entry->data,
+                          FILE_BYTE_ORDER,
+                          {exif.digitalZoomRatio->numerator, exif.digitalZoomRatio->denominator});
+    }
     if (exif.focalLengthIn35mmFilm) {
         entry = addExifEntry(ifdExif, EXIF_TAG_FOCAL_LENGTH_IN_35MM_FILM);
         exif_set_short(entry->data, FILE_BYTE_ORDER, *exif.focalLengthIn35mmFilm);
     }
+    if (exif.sceneCaptureType) {
+        entry = addExifEntry(ifdExif, EXIF_TAG_SCENE_CAPTURE_TYPE);
+        exif_set_short(entry->data, FILE_BYTE_ORDER, *exif.sceneCaptureType);
+    }
+    if (exif.subjectDistanceRange) {
+        entry = addExifEntry(ifdExif, EXIF_TAG_SUBJECT_DISTANCE_RANGE);
+        exif_set_short(entry->data, FILE_BYTE_ORDER, *exif.subjectDistanceRange);
+    }
 }
 #endif
 
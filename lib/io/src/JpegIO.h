@@ -40,6 +40,8 @@ public:
     std::optional<ExifMetadata> readExif() const override;
 #endif
 
+    std::optional<std::vector<uint8_t>> readIccProfile() const override;
+
 private:
     std::unique_ptr<void, JpegDeleter> mHandle;
     std::vector<uint8_t> mHeaderData;
@@ -0,0 +1,89 @@
+// Copyright 2023 Emmanuel Chaboud
+//
+/// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#pragma once
+
+#include <loguru.hpp>
+
+#include <cstdint>
+#include <cstring>
+#include <optional>
+#include <vector>
+
+namespace cxximg {
+
+namespace detail {
+
+/// Extracts the ICC profile stored in the APP2 markers of a JPEG stream.
+///
+/// The profile may be split across several markers (see ICC.1 specification, annex B.4). Each chunk starts with the
+/// "ICC_PROFILE" signature, followed by its sequence number and the chunk count. As the profile is optional, a
+/// malformed or incomplete one is ignored with a warning.
+inline std::optional<std::vector<uint8_t>> extractJpegIccProfile(const uint8_t *data, size_t size) {
+    static constexpr uint8_t MARKER_SOS = 0xDA;
+    static constexpr uint8_t MARKER_APP2 = 0xE2;
+    static constexpr char ICC_SIGNATURE[] = "ICC_PROFILE";
+    static constexpr size_t ICC_HEADER_SIZE = sizeof(ICC_SIGNATURE) + 2;
+
+    std::vector<std::vector<uint8_t>> chunks;
+
+    size_t offset = 2; // skip SOI marker
+    while (offset + 4 <= size && data[offset] == 0xFF && data[offset + 1] != MARKER_SOS) {
+        const uint8_t marker = data[offset + 1];
+        const size_t length = (data[offset + 2] << 8) | data[offset + 3];
+        if (length < 2 || offset + 2 + length > size) {
+            break;
+        }
+
+        const uint8_t *payload = &data[offset + 4];
+        const size_t payloadSize = length - 2;
+
+        if (marker == MARKER_APP2 && payloadSize >= ICC_HEADER_SIZE &&
+            std::memcmp(payload, ICC_SIGNATURE, sizeof(ICC_SIGNATURE)) == 0) {
+            const size_t sequenceNumber = payload[sizeof(ICC_SIGNATURE)];
+            const size_t numChunks = payload[sizeof(ICC_SIGNATURE) + 1];
+
+            if (chunks.empty()) {
+                chunks.resize(numChunks);
+            }
+            if (sequenceNumber < 1 || sequenceNumber > chunks.size() || numChunks != chunks.size()) {
+                LOG_S(WARNING) << "Ignoring ICC profile with invalid chunk " << sequenceNumber << "/" << numChunks;
+                return std::nullopt;
+            }
+
+            chunks[sequenceNumber - 1].assign(payload + ICC_HEADER_SIZE, payload + payloadSize);
+        }
+
+        offset += 2 + length;
+    }
+
+    if (chunks.empty()) {
+        return std::nullopt;
+    }
+
+    std::vector<uint8_t> profile;
+    for (const auto &chunk : chunks) {
+        if (chunk.empty()) {
+            LOG_S(WARNING) << "Ignoring incomplete ICC profile";
+            return std::nullopt;
+        }
+        profile.insert(profile.end(), chunk.begin(), chunk.end());
+    }
+
+    return profile;
+}
+
+} // namespace detail
+
+} // namespace cxximg
@@ -73,6 +73,7 @@ MipiRawReader<PIXEL_PRECISION, RawXPixel, Raw16FromXPixel>::MipiRawReader(const
                               " format: " + std::to_string(*fileInfo.width));
     }
 
+    validateDimensions(*fileInfo.width, *fileInfo.height);
     setDescriptor({LayoutDescriptor::Builder(*fileInfo.width, *fileInfo.height)
                            .pixelType(*fileInfo.pixelType)
                            .pixelPrecision(PIXEL_PRECISION)
@@ -49,6 +49,8 @@ PlainReader::PlainReader(const std::string &path, const Options &options) : Imag
 
     const int width = *fileInfo.width;
     const int height = *fileInfo.height;
+    validateDimensions(width, height);
+
     const auto [imageLayout, pixelType] = guessPixelFromExtension(path);
 
     LayoutDescriptor::Builder builder = LayoutDescriptor::Builder(width, height);
@@ -114,6 +114,7 @@ PngReader::PngReader(const std::string &path, const Options &options)
         throw IOError(MODULE, "Unsupported bit depth " + std::to_string(bitDepth));
     }
 
+    validateDimensions(static_cast<int>(width), static_cast<int>(height));
     setDescriptor({LayoutDescriptor::Builder(width, height)
                            .imageLayout(ImageLayout::INTERLEAVED)
                            .pixelType(colorTypeToPixelType(colorType))
@@ -164,6 +165,19 @@ Image<T> PngReader::read() {
     return image;
 }
 
+std::optional<std::vector<uint8_t>> PngReader::readIccProfile() const {
+    png_charp name = nullptr;
+    int compressionType = 0;
+    png_bytep profile = nullptr;
+    png_uint_32 profileLength = 0;
+
+    if (png_get_iCCP(mPng.get(), mInfo.get(), &name, &compressionType, &profile, &profileLength) == 0) {
+        return std::nullopt;
+    }
+
+    return std::vector<uint8_t>(profile, profile + profileLength);
+}
+
 void PngWriter::write(const Image8u &image) const {
     LOG_SCOPE_F(INFO, "Write PNG (8 bits)");
     LOG_S(INFO) << "Path: " << path();
@@ -216,6 +230,12 @@ void PngWriter::writeImpl(const Image<T> &image) const {
                  PNG_COMPRESSION_TYPE_DEFAULT,
                  PNG_FILTER_TYPE_DEFAULT);
 
+    // embed the ICC profile, if any
+    if (options().iccProfile) {
+        const std::vector<uint8_t> &iccProfile = *options().iccProfile;
+        png_set_iCCP(png, info, "ICC Profile", PNG_COMPRESSION_TYPE_BASE, iccProfile.data(), iccProfile.size());
+    }
+
     // write all chunks up to (but not including) first IDAT
     png_write_info(png, info);
 
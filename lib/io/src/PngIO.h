@@ -49,6 +49,8 @@ public:
     Image8u read8u() override;
     Image16u read16u() override;
 
+    std::optional<std::vector<uint8_t>> readIccProfile() const override;
+
 private:
     template <typename T>
     Image<T> read();
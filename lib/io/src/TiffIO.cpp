@@ -13,6 +13,7 @@
 // limitations under the License.
 
 #include "TiffIO.h"
+#include "ExifUtils.h"
 
 #include <tiffrational.h>
 #include <loguru.hpp>
@@ -80,6 +81,8 @@ TiffReader::TiffReader(const std::string &path, const Options &options)
         throw IOError(MODULE, "Failed to get TIFFTAG_PHOTOMETRIC");
     }
 
+    validateDimensions(static_cast<int>(width), static_cast<int>(height));
+
     LayoutDescriptor::Builder builder = LayoutDescriptor::Builder(width, height);
 
     if (samplesPerPixel == 1) {
@@ -207,6 +210,12 @@ static ExifMetadata::Rational doubleToRational(double x) {
     return r;
 }
 
+static ExifMetadata::SRational doubleToSRational(double x) {
+    ExifMetadata::SRational r;
+    TIFFDoubleToSrational(x, &r.numerator, &r.denominator);
+    return r;
+}
+
 std::optional<ExifMetadata> TiffReader::readExif() const {
     TIFF *tif = mTiff.get();
 
@@ -217,6 +226,11 @@ std::optional<ExifMetadata> TiffReader::readExif() const {
 
     ExifMetadata exif;
 
+    char *documentName = nullptr;
+    if (TIFFGetField(tif, TIFFTAG_DOCUMENTNAME, &documentName) != 0) {
+        exif.documentName = documentName;
+    }
+
     char *imageDescription = nullptr;
     if (TIFFGetField(tif, TIFFTAG_IMAGEDESCRIPTION, &imageDescription) != 0) {
         exif.imageDescription = imageDescription;
@@ -245,6 +259,16 @@ std::optional<ExifMetadata> TiffReader::readExif() const {
         exif.software = software;
     }
 
+    char *artist = nullptr;
+    if (TIFFGetField(tif, TIFFTAG_ARTIST, &artist) != 0) {
+        exif.artist = artist;
+    }
+
+    char *copyright = nullptr;
+    if (TIFFGetField(tif, TIFFTAG_COPYRIGHT, &copyright) != 0) {
+        exif.copyright = copyright;
+    }
+
     TIFFReadEXIFDirectory(tif, exifOffset);
 
     float exposureTime = 0.0f;
@@ -263,30 +287,128 @@ std::optional<ExifMetadata> TiffReader::readExif() const {
         exif.isoSpeedRatings = isoSpeedRatings[0];
     }
 
+#ifdef EXIFTAG_SENSITIVITYTYPE // EXIF 2.3 tags are only known since libtiff 4.2
+    uint16_t sensitivityType = 0;
+    if (TIFFGetField(tif, EXIFTAG_SENSITIVITYTYPE, &sensitivityType) != 0) {
+        exif.sensitivityType = sensitivityType;
+    }
+
+    uint32_t recommendedExposureIndex = 0;
+    if (TIFFGetField(tif, EXIFTAG_RECOMMENDEDEXPOSUREINDEX, &recommendedExposureIndex) != 0) {
+        exif.recommendedExposureIndex = recommendedExposureIndex;
+    }
+#endif
+
     char *dateTimeOriginal = nullptr;
     if (TIFFGetField(tif, EXIFTAG_DATETIMEORIGINAL, &dateTimeOriginal) != 0) {
         exif.dateTimeOriginal = dateTimeOriginal;
     }
 
+    float shutterSpeedValue = 0.0f;
+    if (TIFFGetField(tif, EXIFTAG_SHUTTERSPEEDVALUE, &shutterSpeedValue) != 0) {
+        exif.shutterSpeedValue = doubleToSRational(shutterSpeedValue);
+    }
+
+    float apertureValue = 0.0f;
+    if (TIFFGetField(tif, EXIFTAG_APERTUREVALUE, &apertureValue) != 0) {
+        exif.apertureValue = doubleToRational(apertureValue);
+    }
+
+    float maxApertureValue = 0.0f;
+    if (TIFFGetField(tif, EXIFTAG_MAXAPERTUREVALUE, &maxApertureValue) != 0) {
+        exif.maxApertureValue = doubleToRational(maxApertureValue);
+    }
+
+    float subjectDistance = 0.0f;
+    if (TIFFGetField(tif, EXIFTAG_SUBJECTDISTANCE, &subjectDistance) != 0) {
+        exif.subjectDistance = doubleToRational(subjectDistance);
+    }
+
+    uint16_t lightSource = 0;
+    if (TIFFGetField(tif, EXIFTAG_LIGHTSOURCE, &lightSource) != 0) {
+        exif.lightSource = lightSource;
+    }
+
+    uint16_t flash = 0;
+    if (TIFFGetField(tif, EXIFTAG_FLASH, &flash) != 0) {
+        exif.flash = flash;
+    }
+
     float focalLength = 0.0f;
     if (TIFFGetField(tif, EXIFTAG_FOCALLENGTH, &focalLength) != 0) {
         exif.focalLength = doubleToRational(focalLength);
     }
 
+    uint16_t userCommentSize = 0;
+    uint8_t *userComment = nullptr;
+    if (TIFFGetField(tif, EXIFTAG_USERCOMMENT, &userCommentSize, &userComment) != 0) {
+        exif.userComment = detail::decodeUserComment(userComment, userCommentSize);
+    }
+
+    float flashEnergy = 0.0f;
+    if (TIFFGetField(tif, EXIFTAG_FLASHENERGY, &flashEnergy) != 0) {
+        exif.flashEnergy = doubleToRational(flashEnergy);
+    }
+
+    float exposureIndex = 0.0f;
+    if (TIFFGetField(tif, EXIFTAG_EXPOSUREINDEX, &exposureIndex) != 0) {
+        exif.exposureIndex = doubleToRational(exposureIndex);
+    }
+
+    uint16_t exposureMode = 0;
+    if (TIFFGetField(tif, EXIFTAG_EXPOSUREMODE, &exposureMode) != 0) {
+        exif.exposureMode = exposureMode;
+    }
+
+    uint16_t whiteBalance = 0;
+    if (TIFFGetField(tif, EXIFTAG_WHITEBALANCE, &whiteBalance) != 0) {
+        exif.whiteBalance = whiteBalance;
+    }
+
+    float digitalZoomRatio = 0.0f;
+    if (TIFFGetField(tif, EXIFTAG_DIGITALZOOMRATIO, &digitalZoomRatio) != 0) {
+        exif.digitalZoomRatio = doubleToRational(digitalZoomRatio);
+    }
+
     uint16_t focalLengthIn35mmFilm = 0;
     if (TIFFGetField(tif, EXIFTAG_FOCALLENGTHIN35MMFILM, &focalLengthIn35mmFilm) != 0) {
         exif.focalLengthIn35mmFilm = focalLengthIn35mmFilm;
     }
 
+    uint16_t sceneCaptureType = 0;
+    if (TIFFGetField(tif, EXIFTAG_SCENECAPTURETYPE, &sceneCaptureType) != 0) {
+        exif.sceneCaptureType = sceneCaptureType;
+    }
+
+    uint16_t subjectDistanceRange = 0;
+    if (TIFFGetField(tif, EXIFTAG_SUBJECTDISTANCERANGE, &subjectDistanceRange) != 0) {
+        exif.subjectDistanceRange = subjectDistanceRange;
+    }
+
     TIFFSetDirectory(tif, 0); // go back to main directory
 
     return exif;
 }
 
+std::optional<std::vector<uint8_t>> TiffReader::readIccProfile() const {
+    TIFF *tif = mTiff.get();
+
+    uint32_t count = 0;
+    uint8_t *profile = nullptr;
+    if (TIFFGetField(tif, TIFFTAG_ICCPROFILE, &count, &profile) == 0) {
+        return std::nullopt;
+    }
+
+    return std::vector<uint8_t>(profile, profile + count);
+}
+
 static void populateIfd(TIFF *tif, const ExifMetadata &exif) {
     const uint64_t exifOffset = 0;
     TIFFSetField(tif, TIFFTAG_EXIFIFD, exifOffset); // reserve space, will be filled in populateExif
 
+    if (exif.documentName) {
+        TIFFSetField(tif, TIFFTAG_DOCUMENTNAME, (*exif.documentName).c_str());
+    }
     if (exif.imageDescription) {
         TIFFSetField(tif, TIFFTAG_IMAGEDESCRIPTION, (*exif.imageDescription).c_str());
     }
@@ -302,6 +424,12 @@ static void populateIfd(TIFF *tif, const ExifMetadata &exif) {
     if (exif.software) {
         TIFFSetField(tif, TIFFTAG_SOFTWARE, (*exif.software).c_str());
     }
+    if (exif.artist) {
+        TIFFSetField(tif, TIFFTAG_ARTIST, (*exif.artist).c_str());
+    }
+    if (exif.copyright) {
+        TIFFSetField(tif, TIFFTAG_COPYRIGHT, (*exif.copyright).c_str());
+    }
 }
 
 static void populateExif(TIFF *tif, const ExifMetadata &exif) {
@@ -317,15 +445,66 @@ static void populateExif(TIFF *tif, const ExifMetadata &exif) {
     if (exif.isoSpeedRatings) {
         TIFFSetField(tif, EXIFTAG_ISOSPEEDRATINGS, 1, &(*exif.isoSpeedRatings));
     }
+#ifdef EXIFTAG_SENSITIVITYTYPE
+    if (exif.sensitivityType) {
+        TIFFSetField(tif, EXIFTAG_SENSITIVITYTYPE, *exif.sensitivityType);
+    }
+    if (exif.recommendedExposureIndex) {
+        TIFFSetField(tif, EXIFTAG_RECOMMENDEDEXPOSUREINDEX, *exif.recommendedExposureIndex);
+    }
+#endif
     if (exif.dateTimeOriginal) {
         TIFFSetField(tif, EXIFTAG_DATETIMEORIGINAL, (*exif.dateTimeOriginal).c_str());
     }
+    if (exif.shutterSpeedValue) {
+        TIFFSetField(tif, EXIFTAG_SHUTTERSPEEDVALUE, (*exif.shutterSpeedValue).asFloat());
+    }
+    if (exif.apertureValue) {
+        TIFFSetField(tif, EXIFTAG_APERTUREVALUE, (*exif.apertureValue).asFloat());
+    }
+    if (exif.maxApertureValue) {
+        TIFFSetField(tif, EXIFTAG_MAXAPERTUREVALUE, (*exif.maxApertureValue).asFloat());
+    }
+    if (exif.subjectDistance) {
+        TIFFSetField(tif, EXIFTAG_SUBJECTDISTANCE, (*exif.subjectDistance).asFloat());
+    }
+    if (exif.lightSource) {
+        TIFFSetField(tif, EXIFTAG_LIGHTSOURCE, *exif.lightSource);
+    }
+    if (exif.flash) {
+        TIFFSetField(tif, EXIFTAG_FLASH, *exif.flash);
+    }
     if (exif.focalLength) {
         TIFFSetField(tif, EXIFTAG_FOCALLENGTH, (*exif.focalLength).asFloat());
     }
+    if (exif.userComment) {
+        const std::string userComment = detail::encodeUserComment(*exif.userComment);
+        TIFFSetField(tif, EXIFTAG_USERCOMMENT, static_cast<int>(userComment.size()), userComment.data());
+    }
+    if (exif.flashEnergy) {
+        TIFFSetField(tif, EXIFTAG_FLASHENERGY, (*exif.flashEnergy).asFloat());
+    }
+    if (exif.exposureIndex) {
+        TIFFSetField(tif, EXIFTAG_EXPOSUREINDEX, (*exif.exposureIndex).asFloat());
+    }
+    if (exif.exposureMode) {
+        TIFFSetField(tif, EXIFTAG_EXPOSUREMODE, *exif.exposureMode);
+    }
+    if (exif.whiteBalance) {
+        TIFFSetField(tif, EXIFTAG_WHITEBALANCE, *exif.whiteBalance);
+    }
+    if (exif.digitalZoomRatio) {
+        TIFFSetField(tif, EXIFTAG_DIGITALZOOMRATIO, (*exif.digitalZoomRatio).asFloat());
+    }
     if (exif.focalLengthIn35mmFilm) {
         TIFFSetField(tif, EXIFTAG_FOCALLENGTHIN35MMFILM, *exif.focalLengthIn35mmFilm);
     }
+    if (exif.sceneCaptureType) {
+        TIFFSetField(tif, EXIFTAG_SCENECAPTURETYPE, *exif.sceneCaptureType);
+    }
+    if (exif.subjectDistanceRange) {
+        TIFFSetField(tif, EXIFTAG_SUBJECTDISTANCERANGE, *exif.subjectDistanceRange);
+    }
 }
 
 void TiffWriter::write(const Image8u &image) const {
@@ -432,6 +611,12 @@ void TiffWriter::writeImpl(const Image<T> &image) const {
         populateIfd(tif, metadata->exifMetadata);
     }
 
+    // embed the ICC profile, if any
+    if (options().iccProfile) {
+        const std::vector<uint8_t> &iccProfile = *options().iccProfile;
+        TIFFSetField(tif, TIFFTAG_ICCPROFILE, static_cast<uint32_t>(iccProfile.size()), iccProfile.data());
+    }
+
     // Write image data.
     const T *pStrip = image.data();
     const int64_t rowStride = image.width() * image.numPlanes();
@@ -47,6 +47,7 @@ public:
     Imagef readf() override;
 
     std::optional<ExifMetadata> readExif() const override;
+    std::optional<std::vector<uint8_t>> readIccProfile() const override;
 
 private:
     template <typename T>
@@ -0,0 +1,54 @@
+// Copyright 2023 Emmanuel Chaboud
+//
+/// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "ExifUtils.h"
+
+#include "gtest/gtest.h"
+
+using namespace cxximg;
+
+static std::optional<std::string> decode(const std::string &value) {
+    return detail::decodeUserComment(reinterpret_cast<const uint8_t *>(value.data()), value.size());
+}
+
+TEST(ExifUtilsTest, TestDecodeUserComment) {
+    ASSERT_EQ(decode(std::string("ASCII\0\0\0", 8) + "A comment"), "A comment");
+    ASSERT_EQ(decode(std::string(8, '\0') + "A comment"), "A comment");
+    ASSERT_EQ(decode(std::string("ASCII\0\0\0", 8)), "");
+}
+
+TEST(ExifUtilsTest, TestDecodeUserCommentPadding) {
+    ASSERT_EQ(decode(std::string("ASCII\0\0\0", 8) + "A comment   "), "A comment");
+    ASSERT_EQ(decode(std::string("ASCII\0\0\0", 8) + std::string("A comment\0\0 \0", 13)), "A comment");
+    ASSERT_EQ(decode(std::string("ASCII\0\0\0", 8) + "  A comment"), "  A comment");
+    ASSERT_EQ(decode(std::string("ASCII\0\0\0", 8) + "    "), "");
+    ASSERT_EQ(decode(std::string(8, '\0') + std::string(" \0 \0", 4)), "");
+}
+
+TEST(ExifUtilsTest, TestDecodeUserCommentUnsupportedCode) {
+    // Only ASCII and undefined character codes are supported, other comments are dropped
+    ASSERT_FALSE(decode(std::string("UNICODE\0", 8) + std::string("A\0B\0", 4)).has_value());
+    ASSERT_FALSE(decode(std::string("JIS\0\0\0\0\0", 8) + "A comment").has_value());
+    ASSERT_FALSE(decode(std::string("ASCII   ", 8) + "A comment").has_value());
+
+    // The character code is mandatory
+    ASSERT_FALSE(decode("").has_value());
+    ASSERT_FALSE(decode(std::string("ASCII\0\0", 7)).has_value());
+}
+
+TEST(ExifUtilsTest, TestEncodeUserComment) {
+    ASSERT_EQ(detail::encodeUserComment("A comment"), std::string("ASCII\0\0\0A comment", 17));
+    ASSERT_EQ(detail::encodeUserComment(""), std::string("ASCII\0\0\0", 8));
+    ASSERT_EQ(decode(detail::encodeUserComment("A comment")), "A comment");
+}
@@ -0,0 +1,104 @@
+// Copyright 2023 Emmanuel Chaboud
+//
+/// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "io/ImageIO.h"
+
+#include "CfaIO.h"
+
+#include "gtest/gtest.h"
+
+#include <cstring>
+#include <fstream>
+
+using namespace cxximg;
+
+static ImageReader::Options plainOptions(uint16_t width, uint16_t height) {
+    ImageReader::Options options;
+    options.fileInfo.width = width;
+    options.fileInfo.height = height;
+    options.fileInfo.widthAlignment = 1;
+    options.fileInfo.pixelRepresentation = PixelRepresentation::UINT8;
+    return options;
+}
+
+static std::string writeCfaHeader(const std::string &name, uint32_t blockWidth, uint32_t blockHeight) {
+    CfaHeader header = {};
+    std::memcpy(&header.cfaID, " AFC", sizeof(header.cfaID));
+    header.uCFABlockWidth = blockWidth;
+    header.uCFABlockHeight = blockHeight;
+    header.phase = 2;
+    header.precision = 12;
+
+    const std::string path = ::testing::TempDir() + name;
+    std::ofstream file(path, std::ios::binary);
+    file.write(reinterpret_cast<const char *>(&header), sizeof(header));
+    return path;
+}
+
+TEST(ImageReaderTest, TestPlainDimensionLimits) {
+    ImageReader::Options options = plainOptions(64, 32);
+    options.maxWidth = 64;
+    options.maxHeight = 32;
+    options.maxPixelCount = 64 * 32;
+
+    const auto reader = io::makeReader("image.y8", options);
+    ASSERT_EQ(reader->layoutDescriptor().width, 64);
+    ASSERT_EQ(reader->layoutDescriptor().height, 32);
+
+    options.maxWidth = 63;
+    EXPECT_THROW(io::makeReader("image.y8", options), IOError);
+    options.maxWidth.reset();
+
+    options.maxHeight = 31;
+    EXPECT_THROW(io::makeReader("image.y8", options), IOError);
+    options.maxHeight.reset();
+
+    options.maxPixelCount = 64 * 32 - 1;
+    EXPECT_THROW(io::makeReader("image.y8", options), IOError);
+}
+
+TEST(ImageReaderTest, TestPlainInvalidDimensions) {
+    EXPECT_THROW(io::makeReader("image.y8", plainOptions(0, 32)), IOError);
+    EXPECT_THROW(io::makeReader("image.y8", plainOptions(64, 0)), IOError);
+}
+
+TEST(ImageReaderTest, TestCfaDimensionLimits) {
+    const std::string path = writeCfaHeader("limits.cfa", 32, 16);
+
+    ImageReader::Options options;
+    options.maxWidth = 64;
+    options.maxHeight = 32;
+    options.maxPixelCount = 64 * 32;
+
+    const auto reader = io::makeReader(path, options);
+    ASSERT_EQ(reader->layoutDescriptor().width, 64);
+    ASSERT_EQ(reader->layoutDescriptor().height, 32);
+
+    options.maxWidth = 63;
+    EXPECT_THROW(io::makeReader(path, options), IOError);
+    options.maxWidth.reset();
+
+    options.maxHeight = 31;
+    EXPECT_THROW(io::makeReader(path, options), IOError);
+    options.maxHeight.reset();
+
+    options.maxPixelCount = 64 * 32 - 1;
+    EXPECT_THROW(io::makeReader(path, options), IOError);
+}
+
+TEST(ImageReaderTest, TestCfaInvalidDimensions) {
+    // header values that wrap to negative dimensions
+    EXPECT_THROW(io::makeReader(writeCfaHeader("negative.cfa", 0xFFFFFFFF, 16), {}), IOError);
+    EXPECT_THROW(io::makeReader(writeCfaHeader("empty.cfa", 32, 0), {}), IOError);
+}
@@ -0,0 +1,95 @@
+// Copyright 2023 Emmanuel Chaboud
+//
+/// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "JpegUtils.h"
+
+#include "gtest/gtest.h"
+
+#include <string>
+
+using namespace cxximg;
+
+static std::vector<uint8_t> iccSegment(uint8_t sequenceNumber, uint8_t numChunks, const std::vector<uint8_t> &chunk) {
+    static constexpr char ICC_SIGNATURE[] = "ICC_PROFILE";
+
+    const size_t length = 2 + sizeof(ICC_SIGNATURE) + 2 + chunk.size();
+    std::vector<uint8_t> segment = {0xFF, 0xE2, static_cast<uint8_t>(length >> 8), static_cast<uint8_t>(length)};
+    segment.insert(segment.end(), ICC_SIGNATURE, ICC_SIGNATURE + sizeof(ICC_SIGNATURE));
+    segment.push_back(sequenceNumber);
+    segment.push_back(numChunks);
+    segment.insert(segment.end(), chunk.begin(), chunk.end());
+    return segment;
+}
+
+static std::vector<uint8_t> jpegHeader(const std::vector<std::vector<uint8_t>> &segments) {
+    std::vector<uint8_t> header = {0xFF, 0xD8};
+    for (const auto &segment : segments) {
+        header.insert(header.end(), segment.begin(), segment.end());
+    }
+    header.insert(header.end(), {0xFF, 0xDA, 0x00, 0x02});
+    return header;
+}
+
+static std::optional<std::vector<uint8_t>> extract(const std::vector<uint8_t> &header) {
+    return detail::extractJpegIccProfile(header.data(), header.size());
+}
+
+TEST(JpegUtilsTest, TestIccProfileSingleChunk) {
+    const std::vector<uint8_t> header = jpegHeader({iccSegment(1, 1, {1, 2, 3})});
+
+    const auto profile = extract(header);
+    ASSERT_TRUE(profile.has_value());
+    ASSERT_EQ(*profile, std::vector<uint8_t>({1, 2, 3}));
+}
+
+TEST(JpegUtilsTest, TestIccProfileChunksOutOfOrder) {
+    const std::vector<uint8_t> app1 = {0xFF, 0xE1, 0x00, 0x04, 'E', 'x'};
+    const std::vector<uint8_t> header =
+            jpegHeader({iccSegment(3, 3, {5, 6}), app1, iccSegment(1, 3, {1, 2}), iccSegment(2, 3, {3, 4})});
+
+    const auto profile = extract(header);
+    ASSERT_TRUE(profile.has_value());
+    ASSERT_EQ(*profile, std::vector<uint8_t>({1, 2, 3, 4, 5, 6}));
+}
+
+TEST(JpegUtilsTest, TestNoIccProfile) {
+    const std::vector<uint8_t> app1 = {0xFF, 0xE1, 0x00, 0x04, 'E', 'x'};
+
+    ASSERT_FALSE(extract(jpegHeader({})).has_value());
+    ASSERT_FALSE(extract(jpegHeader({app1})).has_value());
+}
+
+TEST(JpegUtilsTest, TestIccProfileMissingChunk) {
+    const std::vector<uint8_t> header = jpegHeader({iccSegment(1, 3, {1, 2}), iccSegment(3, 3, {5, 6})});
+
+    ASSERT_FALSE(extract(header).has_value());
+}
+
+TEST(JpegUtilsTest, TestIccProfileInconsistentChunks) {
+    // chunk count differs between chunks
+    ASSERT_FALSE(extract(jpegHeader({iccSegment(1, 2, {1, 2}), iccSegment(2, 3, {3, 4})})).has_value());
+
+    // sequence numbers start at 1
+    ASSERT_FALSE(extract(jpegHeader({iccSegment(0, 1, {1, 2})})).has_value());
+
+    // sequence number above the chunk count
+    ASSERT_FALSE(extract(jpegHeader({iccSegment(1, 2, {1, 2}), iccSegment(3, 2, {3, 4})})).has_value());
+}
+
+TEST(JpegUtilsTest, TestIccProfileTruncated) {
+    std::vector<uint8_t> header = jpegHeader({iccSegment(1, 2, {1, 2}), iccSegment(2, 2, {3, 4, 5, 6})});
+    header.resize(header.size() - 8); // cut in the middle of the second chunk
+
+    ASSERT_FALSE(extract(header).has_value());
+}
@@ -31,19 +31,72 @@ struct ExifMetadata final {
         inline double asDouble() const noexcept { return static_cast<double>(numerator) / denominator; }
     };
 
-    std::optional<uint16_t> imageWidth;            ///< Image width reported in EXIF data
-    std::optional<uint16_t> imageHeight;           ///< Image height reported in EXIF data
-    std::optional<std::string> imageDescription;   ///< Image description
-    std::optional<std::string> make;               ///< Camera manufacturer's name
-    std::optional<std::string> model;              ///< Camera model
-    std::optional<uint16_t> orientation;           ///< Image orientation
-    std::optional<std::string> software;           ///< Software used
-    std::optional<Rational> exposureTime;          ///< Exposure time in seconds
-    std::optional<Rational> fNumber;               ///< F/stop
-    std::optional<uint16_t> isoSpeedRatings;       ///< ISO speed
-    std::optional<std::string> dateTimeOriginal;   ///< Date when original image was taken
-    std::optional<Rational> focalLength;           ///< Focal length of lens in millimeters
-    std::optional<uint16_t> focalLengthIn35mmFilm; ///< Focal length of lens in millimeters (35mm equivalent)
+    struct SRational final {
+        int32_t numerator = 0;
+        int32_t denominator = 1;
+
+        inline double asFloat() const noexcept { return static_cast<float>(numerator) / denominator; }
+        inline double asDouble() const noexcept { return static_cast<double>(numerator) / denominator; }
+    };
+
+    /// Flash status, decomposed from the Flash tag value.
+    struct FlashStatus final {
+        enum class Return { NO_DETECTION_FUNCTION, RESERVED, NOT_DETECTED, DETECTED };
+        enum class Mode { UNKNOWN, COMPULSORY_FIRING, COMPULSORY_SUPPRESSION, AUTO };
+
+        bool fired = false;                                 ///< Flash fired
+        Return returnLight = Return::NO_DETECTION_FUNCTION; ///< Strobe return light detection
+        Mode mode = Mode::UNKNOWN;                          ///< Flash firing mode
+        bool present = false;                               ///< Flash function present
+        bool redEyeReduction = false;                       ///< Red-eye reduction mode
+
+        static inline FlashStatus decode(uint16_t flash) noexcept {
+            FlashStatus status;
+            status.fired = (flash & 0x01) != 0;
+            status.returnLight = static_cast<Return>((flash >> 1) & 0x03);
+            status.mode = static_cast<Mode>((flash >> 3) & 0x03);
+            status.present = (flash & 0x20) == 0;
+            status.redEyeReduction = (flash & 0x40) != 0;
+            return status;
+        }
+    };
+
+    std::optional<uint16_t> imageWidth;               ///< Image width reported in EXIF data
+    std::optional<uint16_t> imageHeight;              ///< Image height reported in EXIF data
+    std::optional<std::string> documentName;          ///< Name of the document the image was scanned from
+    std::optional<std::string> imageDescription;      ///< Image description
+    std::optional<std::string> make;                  ///< Camera manufacturer's name
+    std::optional<std::string> model;                 ///< Camera model
+    std::optional<uint16_t> orientation;              ///< Image orientation
+    std::optional<std::string> software;              ///< Software used
+    std::optional<std::string> artist;                ///< Person who created the image
+    std::optional<std::string> copyright;             ///< Copyright holder
+    std::optional<std::string> dngVersion;            ///< DNG specification version, such as "1.4.0.0"
+    std::optional<std::string> uniqueCameraModel;     ///< Unique non-localized camera model name of a DNG
+    std::optional<std::string> originalRawFileName;   ///< File name of the raw file a DNG was converted from
+    std::optional<std::string> originalRawFileDigest; ///< MD5 digest of the original raw file, in hexadecimal
+    std::optional<Rational> exposureTime;             ///< Exposure time in seconds
+    std::optional<Rational> fNumber;                  ///< F/stop
+    std::optional<uint16_t> isoSpeedRatings;          ///< ISO speed
+    std::optional<uint16_t> sensitivityType;          ///< Sensitivity convention of the ISO speed
+    std::optional<uint32_t> recommendedExposureIndex; ///< Recommended exposure index
+    std::optional<std::string> dateTimeOriginal;      ///< Date when original image was taken
+    std::optional<SRational> shutterSpeedValue;       ///< Shutter speed in APEX unit
+    std::optional<Rational> apertureValue;            ///< Lens aperture in APEX unit
+    std::optional<Rational> maxApertureValue;         ///< Smallest F number of lens in APEX unit
+    std::optional<Rational> subjectDistance;          ///< Distance to the subject in meters
+    std::optional<uint16_t> lightSource;              ///< Light source
+    std::optional<uint16_t> flash;                    ///< Flash status, see FlashStatus to decompose it
+    std::optional<Rational> focalLength;              ///< Focal length of lens in millimeters
+    std::optional<std::string> userComment;           ///< User comments
+    std::optional<Rational> flashEnergy;              ///< Flash energy in BCPS
+    std::optional<Rational> exposureIndex;            ///< Exposure index
+    std::optional<uint16_t> exposureMode;             ///< Exposure mode
+    std::optional<uint16_t> whiteBalance;             ///< White balance mode
+    std::optional<Rational> digitalZoomRatio;         ///< Digital zoom ratio
+    std::optional<uint16_t> focalLengthIn35mmFilm;    ///< Focal length of lens in millimeters (35mm equivalent)
+    std::optional<uint16_t> sceneCaptureType;         ///< Scene capture type
+    std::optional<uint16_t> subjectDistanceRange;     ///< Distance range to the subject
 };
 
 } // namespace cxximg
@@ -21,6 +21,7 @@
 #include "math/ColorSpace.h"
 #include "math/DynamicMatrix.h"
 #include "math/Matrix.h"
+#include "math/Point.h"
 
 #include <cstdint>
 #include <optional>
@@ -95,10 +96,13 @@ struct ImageMetadata final {
 
     struct CalibrationData final {
         std::optional<std::variant<int, float>> blackLevel; ///< Black level
+        std::optional<std::vector<float>> blackLevelDeltaH; ///< Per-column offsets to blackLevel, one per image column
+        std::optional<std::vector<float>> blackLevelDeltaV; ///< Per-row offsets to blackLevel, one per image row
         std::optional<std::variant<int, float>> whiteLevel; ///< White level
         std::optional<DynamicMatrix> vignetting;            ///< Luminance lens shading correction map
         std::optional<Matrix3> colorMatrix;                 ///< Color matrix
         std::optional<RgbColorSpace> colorMatrixTarget;     ///< Target color space of color matrix
+        std::optional<std::vector<Point2>> toneCurve;       ///< Tone curve control points, in [0, 1] range
     };
 
     using SemanticMasks = std::unordered_multimap<SemanticLabel, SemanticMask>;
@@ -0,0 +1,47 @@
+// Copyright 2023 Emmanuel Chaboud
+//
+/// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "model/ExifMetadata.h"
+
+#include "gtest/gtest.h"
+
+using namespace cxximg;
+
+using FlashStatus = ExifMetadata::FlashStatus;
+
+TEST(ExifMetadataTest, TestFlashStatusDecode) {
+    // Flash fired, auto mode, red-eye reduction mode
+    FlashStatus status = FlashStatus::decode(0x59);
+    ASSERT_TRUE(status.fired);
+    ASSERT_EQ(status.returnLight, FlashStatus::Return::NO_DETECTION_FUNCTION);
+    ASSERT_EQ(status.mode, FlashStatus::Mode::AUTO);
+    ASSERT_TRUE(status.present);
+    ASSERT_TRUE(status.redEyeReduction);
+
+    // Flash fired, compulsory flash mode, return light detected
+    status = FlashStatus::decode(0x0f);
+    ASSERT_TRUE(status.fired);
+    ASSERT_EQ(status.returnLight, FlashStatus::Return::DETECTED);
+    ASSERT_EQ(status.mode, FlashStatus::Mode::COMPULSORY_FIRING);
+    ASSERT_TRUE(status.present);
+    ASSERT_FALSE(status.redEyeReduction);
+
+    // No flash function
+    status = FlashStatus::decode(0x20);
+    ASSERT_FALSE(status.fired);
+    ASSERT_EQ(status.returnLight, FlashStatus::Return::NO_DETECTION_FUNCTION);
+    ASSERT_EQ(status.mode, FlashStatus::Mode::UNKNOWN);
+    ASSERT_FALSE(status.present);
+    ASSERT_FALSE(status.redEyeReduction);
+}
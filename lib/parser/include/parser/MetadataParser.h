@@ -44,6 +44,17 @@ ImageMetadata readMetadata(const std::string& metadataPath);
 /// Write metadata to then given file.
 void writeMetadata(const ImageMetadata& metadata, const std::string& outputPath);
 
+/// Parse metadata from the given JSON string.
+ImageMetadata parseMetadata(const std::string& json);
+
+/// Serialize metadata to a JSON string.
+std::string serializeMetadata(const ImageMetadata& metadata);
+
+/// Serialize EXIF metadata to a JSON string using ExifTool group and tag names.
+///
+/// The output follows the layout of `exiftool -j -n -G1`, so that both can be compared directly.
+std::string serializeExifTool(const ExifMetadata& exif, const std::string& sourceFile);
+
 } // namespace parser
 
 } // namespace cxximg
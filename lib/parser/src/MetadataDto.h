@@ -200,6 +200,42 @@ inline void write_json_value(const ExifMetadata::Rational& rational,
     object.PushBack(rational.denominator, allocator);
 }
 
+// ExifMetadata::SRational struct
+
+inline void read_json_value(ExifMetadata::SRational& srational, const rapidjson::Value& object) {
+    if (!object.IsArray() || object.Size() != 2) {
+        throw json_dto::ex_t("Invalid EXIF signed rational value");
+    }
+    json_dto::read_json_value(srational.numerator, object[0]);
+    json_dto::read_json_value(srational.denominator, object[1]);
+}
+
+inline void write_json_value(const ExifMetadata::SRational& srational,
+                             rapidjson::Value& object,
+                             rapidjson::MemoryPoolAllocator<>& allocator) {
+    object.SetArray();
+    object.PushBack(srational.numerator, allocator);
+    object.PushBack(srational.denominator, allocator);
+}
+
+// Point2 struct
+
+inline void read_json_value(Point2& point, const rapidjson::Value& object) {
+    if (!object.IsArray() || object.Size() != 2) {
+        throw json_dto::ex_t("Invalid point value");
+    }
+    json_dto::read_json_value(point.x, object[0]);
+    json_dto::read_json_value(point.y, object[1]);
+}
+
+inline void write_json_value(const Point2& point,
+                             rapidjson::Value& object,
+                             rapidjson::MemoryPoolAllocator<>& allocator) {
+    object.SetArray();
+    object.PushBack(point.x, allocator);
+    object.PushBack(point.y, allocator);
+}
+
 // ImageMetadata::ColorShading struct
 
 inline void read_json_value(ImageMetadata::ColorShading& colorShading, const rapidjson::Value& object) {
@@ -266,17 +302,40 @@ template <typename JsonIo>
 void json_io(JsonIo& io, ExifMetadata& exifMetadata) {
     io& json_dto::optional("imageWidth", exifMetadata.imageWidth, std::nullopt) &
             json_dto::optional("imageHeight", exifMetadata.imageHeight, std::nullopt) &
+            json_dto::optional("documentName", exifMetadata.documentName, std::nullopt) &
             json_dto::optional("imageDescription", exifMetadata.imageDescription, std::nullopt) &
             json_dto::optional("make", exifMetadata.make, std::nullopt) &
             json_dto::optional("model", exifMetadata.model, std::nullopt) &
             json_dto::optional("orientation", exifMetadata.orientation, std::nullopt) &
             json_dto::optional("software", exifMetadata.software, std::nullopt) &
+            json_dto::optional("artist", exifMetadata.artist, std::nullopt) &
+            json_dto::optional("copyright", exifMetadata.copyright, std::nullopt) &
+            json_dto::optional("dngVersion", exifMetadata.dngVersion, std::nullopt) &
+            json_dto::optional("uniqueCameraModel", exifMetadata.uniqueCameraModel, std::nullopt) &
+            json_dto::optional("originalRawFileName", exifMetadata.originalRawFileName, std::nullopt) &
+            json_dto::optional("originalRawFileDigest", exifMetadata.originalRawFileDigest, std::nullopt) &
             json_dto::optional("exposureTime", exifMetadata.exposureTime, std::nullopt) &
             json_dto::optional("fNumber", exifMetadata.fNumber, std::nullopt) &
             json_dto::optional("isoSpeedRatings", exifMetadata.isoSpeedRatings, std::nullopt) &
+            json_dto::optional("sensitivityType", exifMetadata.sensitivityType, std::nullopt) &
+            json_dto::optional("recommendedExposureIndex", exifMetadata.recommendedExposureIndex, std::nullopt) &
             json_dto::optional("dateTimeOriginal", exifMetadata.dateTimeOriginal, std::nullopt) &
+            json_dto::optional("shutterSpeedValue", exifMetadata.shutterSpeedValue, std::nullopt) &
+            json_dto::optional("apertureValue", exifMetadata.apertureValue, std::nullopt) &
+            json_dto::optional("maxApertureValue", exifMetadata.maxApertureValue, std::nullopt) &
+            json_dto::optional("subjectDistance", exifMetadata.subjectDistance, std::nullopt) &
+            json_dto::optional("lightSource", exifMetadata.lightSource, std::nullopt) &
+            json_dto::optional("flash", exifMetadata.flash, std::nullopt) &
             json_dto::optional("focalLength", exifMetadata.focalLength, std::nullopt) &
-            json_dto::optional("focalLengthIn35mmFilm", exifMetadata.focalLengthIn35mmFilm, std::nullopt);
+            json_dto::optional("userComment", exifMetadata.userComment, std::nullopt) &
+            json_dto::optional("flashEnergy", exifMetadata.flashEnergy, std::nullopt) &
+            json_dto::optional("exposureIndex", exifMetadata.exposureIndex, std::nullopt) &
+            json_dto::optional("exposureMode", exifMetadata.exposureMode, std::nullopt) &
+            json_dto::optional("whiteBalance", exifMetadata.whiteBalance, std::nullopt) &
+            json_dto::optional("digitalZoomRatio", exifMetadata.digitalZoomRatio, std::nullopt) &
+            json_dto::optional("focalLengthIn35mmFilm", exifMetadata.focalLengthIn35mmFilm, std::nullopt) &
+            json_dto::optional("sceneCaptureType", exifMetadata.sceneCaptureType, std::nullopt) &
+            json_dto::optional("subjectDistanceRange", exifMetadata.subjectDistanceRange, std::nullopt);
 }
 
 template <typename JsonIo>
@@ -311,10 +370,13 @@ void json_io(JsonIo& io, ImageMetadata::ShootingParams& shootingParams) {
 template <typename JsonIo>
 void json_io(JsonIo& io, ImageMetadata::CalibrationData& calibrationData) {
     io& json_dto::optional("blackLevel", calibrationData.blackLevel, std::nullopt) &
+            json_dto::optional("blackLevelDeltaH", calibrationData.blackLevelDeltaH, std::nullopt) &
+            json_dto::optional("blackLevelDeltaV", calibrationData.blackLevelDeltaV, std::nullopt) &
             json_dto::optional("whiteLevel", calibrationData.whiteLevel, std::nullopt) &
             json_dto::optional("vignetting", calibrationData.vignetting, std::nullopt) &
             json_dto::optional("colorMatrix", calibrationData.colorMatrix, std::nullopt) &
-            json_dto::optional("colorMatrixTarget", calibrationData.colorMatrixTarget, std::nullopt);
+            json_dto::optional("colorMatrixTarget", calibrationData.colorMatrixTarget, std::nullopt) &
+            json_dto::optional("toneCurve", calibrationData.toneCurve, std::nullopt);
 }
 
 template <typename JsonIo>
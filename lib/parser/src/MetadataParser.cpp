@@ -17,7 +17,13 @@
 #include "parser/Exceptions.h"
 
 #include <loguru.hpp>
+#include <rapidjson/prettywriter.h>
+#include <rapidjson/stringbuffer.h>
 
+#include <algorithm>
+#include <cmath>
+#include <cstdio>
+#include <cstring>
 #include <filesystem>
 #include <fstream>
 
@@ -91,6 +97,121 @@ void writeMetadata(const ImageMetadata& metadata, const std::string& outputPath)
     }
 }
 
+ImageMetadata parseMetadata(const std::string& json) {
+    ImageMetadata metadata;
+
+    try {
+        json_dto::from_json(json, metadata);
+    } catch (const json_dto::ex_t& e) {
+        throw ParserError(e.what());
+    }
+
+    return metadata;
+}
+
+std::string serializeMetadata(const ImageMetadata& metadata) {
+    try {
+        return json_dto::to_json(metadata, json_dto::pretty_writer_params_t{});
+    } catch (const json_dto::ex_t& e) {
+        throw ParserError(e.what());
+    }
+}
+
+std::string serializeExifTool(const ExifMetadata& exif, const std::string& sourceFile) {
+    rapidjson::StringBuffer buffer;
+    rapidjson::PrettyWriter<rapidjson::StringBuffer> writer(buffer);
+
+    const auto writeString = [&](const char* tag, const std::optional<std::string>& value) {
+        if (value) {
+            writer.Key(tag);
+            writer.String(value->c_str());
+        }
+    };
+
+    const auto writeInteger = [&](const char* tag, const auto& value) {
+        if (value) {
+            writer.Key(tag);
+            writer.Uint(*value);
+        }
+    };
+
+    const auto writeDecimal = [&](double value) {
+        // ExifTool prints numbers the way Perl does, rounded to 15 significant digits
+        char number[32];
+        std::snprintf(number, sizeof(number), "%.15g", value);
+        writer.RawValue(number, std::strlen(number), rapidjson::kNumberType);
+    };
+
+    const auto writeRational = [&](const char* tag, const std::optional<ExifMetadata::Rational>& value) {
+        // ExifTool prints numerical values as decimals when -n is given
+        if (value && value->denominator != 0) {
+            writer.Key(tag);
+            writeDecimal(value->asDouble());
+        }
+    };
+
+    const auto writeApex = [&](const char* tag, const auto& value, double (*convert)(double)) {
+        // ExifTool converts APEX values back to seconds and F numbers, even when -n is given
+        if (value && value->denominator != 0) {
+            writer.Key(tag);
+            writeDecimal(convert(value->asDouble()));
+        }
+    };
+
+    writer.StartArray();
+    writer.StartObject();
+
+    writer.Key("SourceFile");
+    writer.String(sourceFile.c_str());
+
+    writeInteger("IFD0:ImageWidth", exif.imageWidth);
+    writeInteger("IFD0:ImageHeight", exif.imageHeight);
+    writeString("IFD0:DocumentName", exif.documentName);
+    writeString("IFD0:ImageDescription", exif.imageDescription);
+    writeString("IFD0:Make", exif.make);
+    writeString("IFD0:Model", exif.model);
+    writeInteger("IFD0:Orientation", exif.orientation);
+    writeString("IFD0:Software", exif.software);
+    writeString("IFD0:Artist", exif.artist);
+    writeString("IFD0:Copyright", exif.copyright);
+    if (exif.dngVersion) {
+        // ExifTool prints the version bytes separated by spaces when -n is given
+        std::string dngVersion = *exif.dngVersion;
+        std::replace(dngVersion.begin(), dngVersion.end(), '.', ' ');
+        writeString("IFD0:DNGVersion", dngVersion);
+    }
+    writeString("IFD0:UniqueCameraModel", exif.uniqueCameraModel);
+    writeString("IFD0:OriginalRawFileName", exif.originalRawFileName);
+    writeString("IFD0:OriginalRawFileDigest", exif.originalRawFileDigest);
+    writeRational("ExifIFD:ExposureTime", exif.exposureTime);
+    writeRational("ExifIFD:FNumber", exif.fNumber);
+    writeInteger("ExifIFD:ISO", exif.isoSpeedRatings);
+    writeInteger("ExifIFD:SensitivityType", exif.sensitivityType);
+    writeInteger("ExifIFD:RecommendedExposureIndex", exif.recommendedExposureIndex);
+    writeString("ExifIFD:DateTimeOriginal", exif.dateTimeOriginal);
+    writeApex("ExifIFD:ShutterSpeedValue", exif.shutterSpeedValue, [](double tv) { return std::pow(2.0, -tv); });
+    writeApex("ExifIFD:ApertureValue", exif.apertureValue, [](double av) { return std::pow(2.0, av / 2.0); });
+    writeApex("ExifIFD:MaxApertureValue", exif.maxApertureValue, [](double av) { return std::pow(2.0, av / 2.0); });
+    writeRational("ExifIFD:SubjectDistance", exif.subjectDistance);
+    writeInteger("ExifIFD:LightSource", exif.lightSource);
+    writeInteger("ExifIFD:Flash", exif.flash);
+    writeRational("ExifIFD:FocalLength", exif.focalLength);
+    writeString("ExifIFD:UserComment", exif.userComment);
+    writeRational("ExifIFD:FlashEnergy", exif.flashEnergy);
+    writeRational("ExifIFD:ExposureIndex", exif.exposureIndex);
+    writeInteger("ExifIFD:ExposureMode", exif.exposureMode);
+    writeInteger("ExifIFD:WhiteBalance", exif.whiteBalance);
+    writeRational("ExifIFD:DigitalZoomRatio", exif.digitalZoomRatio);
+    writeInteger("ExifIFD:FocalLengthIn35mmFormat", exif.focalLengthIn35mmFilm);
+    writeInteger("ExifIFD:SceneCaptureType", exif.sceneCaptureType);
+    writeInteger("ExifIFD:SubjectDistanceRange", exif.subjectDistanceRange);
+
+    writer.EndObject();
+    writer.EndArray();
+
+    return buffer.GetString();
+}
+
 } // namespace parser
 
 } // namespace cxximg
@@ -31,17 +31,40 @@ TEST(MetadataParserTest, TestSerializationFull) {
                          .widthAlignment = 1},
             .exifMetadata = {.imageWidth = 4000,
                              .imageHeight = 3000,
+                             .documentName = "Scan",
                              .imageDescription = "My description",
                              .make = "Parser",
                              .model = "Test",
                              .orientation = 1,
                              .software = "",
+                             .artist = "Me",
+                             .copyright = "Copyright 2023",
+                             .dngVersion = "1.4.0.0",
+                             .uniqueCameraModel = "Parser Test",
+                             .originalRawFileName = "IMG_0001.RAW",
+                             .originalRawFileDigest = "0123456789abcdef0123456789abcdef",
                              .exposureTime = ExifMetadata::Rational{1, 100},
                              .fNumber = ExifMetadata::Rational{56, 10},
                              .isoSpeedRatings = 100,
+                             .sensitivityType = 2,
+                             .recommendedExposureIndex = 100,
                              .dateTimeOriginal = "2023:08:25 17:13:31",
+                             .shutterSpeedValue = ExifMetadata::SRational{664, 100},
+                             .apertureValue = ExifMetadata::Rational{497, 100},
+                             .maxApertureValue = ExifMetadata::Rational{297, 100},
+                             .subjectDistance = ExifMetadata::Rational{3, 2},
+                             .lightSource = 21,
+                             .flash = 16,
                              .focalLength = ExifMetadata::Rational{35, 1},
-                             .focalLengthIn35mmFilm = 50},
+                             .userComment = "My comment",
+                             .flashEnergy = ExifMetadata::Rational{1000, 1},
+                             .exposureIndex = ExifMetadata::Rational{100, 1},
+                             .exposureMode = 0,
+                             .whiteBalance = 0,
+                             .digitalZoomRatio = ExifMetadata::Rational{2, 1},
+                             .focalLengthIn35mmFilm = 50,
+                             .sceneCaptureType = 0,
+                             .subjectDistanceRange = 3},
             .shootingParams = {.aperture = 5.6f,
                                .exposureTime = 0.01f,
                                .totalGain = 1.0f,
@@ -49,10 +72,13 @@ TEST(MetadataParserTest, TestSerializationFull) {
                                .ispGain = 1.0f,
                                .zoom = ImageMetadata::ROI{0.05f, 0.1f, 0.9f, 0.8f}},
             .calibrationData = {.blackLevel = 64,
+                                .blackLevelDeltaH = std::vector<float>{0.5f, -0.5f},
+                                .blackLevelDeltaV = std::vector<float>{1.0f, 0.0f},
                                 .whiteLevel = 1024.0f,
                                 .vignetting = DynamicMatrix{{3.0f, 1.5f, 3.0f}, {1.5f, 1.0f, 1.5f}, {3.0f, 1.5f, 3.0f}},
                                 .colorMatrix = Matrix3{{1.0f, 0.0f, 0.0f}, {0.0f, 1.0f, 0.0f}, {0.0f, 0.0f, 1.0f}},
-                                .colorMatrixTarget = RgbColorSpace::SRGB},
+                                .colorMatrixTarget = RgbColorSpace::SRGB,
+                                .toneCurve = std::vector<Point2>{{0.0f, 0.0f}, {0.25f, 0.375f}, {1.0f, 1.0f}}},
             .cameraControls = {.whiteBalance = ImageMetadata::WhiteBalance{2.0f, 1.5},
                                .colorShading = ImageMetadata::ColorShading{{{1.2f, 1.05f, 1.2f},
                                                                             {1.05f, 1.0f, 1.05f},
@@ -81,11 +107,18 @@ TEST(MetadataParserTest, TestSerializationFull) {
     "exifMetadata": {
         "imageWidth": 4000,
         "imageHeight": 3000,
+        "documentName": "Scan",
         "imageDescription": "My description",
         "make": "Parser",
         "model": "Test",
         "orientation": 1,
         "software": "",
+        "artist": "Me",
+        "copyright": "Copyright 2023",
+        "dngVersion": "1.4.0.0",
+        "uniqueCameraModel": "Parser Test",
+        "originalRawFileName": "IMG_0001.RAW",
+        "originalRawFileDigest": "0123456789abcdef0123456789abcdef",
         "exposureTime": [
             1,
             100
@@ -95,12 +128,49 @@ TEST(MetadataParserTest, TestSerializationFull) {
             10
         ],
         "isoSpeedRatings": 100,
+        "sensitivityType": 2,
+        "recommendedExposureIndex": 100,
         "dateTimeOriginal": "2023:08:25 17:13:31",
+        "shutterSpeedValue": [
+            664,
+            100
+        ],
+        "apertureValue": [
+            497,
+            100
+        ],
+        "maxApertureValue": [
+            297,
+            100
+        ],
+        "subjectDistance": [
+            3,
+            2
+        ],
+        "lightSource": 21,
+        "flash": 16,
         "focalLength": [
             35,
             1
         ],
-        "focalLengthIn35mmFilm": 50
+        "userComment": "My comment",
+        "flashEnergy": [
+            1000,
+            1
+        ],
+        "exposureIndex": [
+            100,
+            1
+        ],
+        "exposureMode": 0,
+        "whiteBalance": 0,
+        "digitalZoomRatio": [
+            2,
+            1
+        ],
+        "focalLengthIn35mmFilm": 50,
+        "sceneCaptureType": 0,
+        "subjectDistanceRange": 3
     },
     "shootingParams": {
         "aperture": 5.599999904632568,
@@ -117,6 +187,14 @@ TEST(MetadataParserTest, TestSerializationFull) {
     },
     "calibrationData": {
         "blackLevel": 64,
+        "blackLevelDeltaH": [
+            0.5,
+            -0.5
+        ],
+        "blackLevelDeltaV": [
+            1.0,
+            0.0
+        ],
         "whiteLevel": 1024.0,
         "vignetting": [
             [
@@ -152,7 +230,21 @@ TEST(MetadataParserTest, TestSerializationFull) {
                 1.0
             ]
         ],
-        "colorMatrixTarget": "srgb"
+        "colorMatrixTarget": "srgb",
+        "toneCurve": [
+            [
+                0.0,
+                0.0
+            ],
+            [
+                0.25,
+                0.375
+            ],
+            [
+                1.0,
+                1.0
+            ]
+        ]
     },
     "cameraControls": {
         "whiteBalance": [
@@ -248,3 +340,90 @@ TEST(MetadataParserTest, TestDeserializationPartial) {
 
     ASSERT_EQ(ref, json);
 }
+
+TEST(MetadataParserTest, TestSerializationString) {
+    ImageMetadata metadata;
+    metadata.fileInfo.pixelType = PixelType::BAYER_RGGB;
+    metadata.fileInfo.width = 4000;
+    metadata.fileInfo.height = 3000;
+    metadata.exifMetadata.make = "Parser";
+    metadata.exifMetadata.isoSpeedRatings = 100;
+    metadata.calibrationData.blackLevel = 64;
+    metadata.calibrationData.whiteLevel = 1023;
+
+    std::string json = parser::serializeMetadata(metadata);
+    const char* ref = R"V0G0N({
+    "fileInfo": {
+        "pixelType": "bayer_rggb",
+        "width": 4000,
+        "height": 3000
+    },
+    "exifMetadata": {
+        "make": "Parser",
+        "isoSpeedRatings": 100
+    },
+    "shootingParams": {},
+    "calibrationData": {
+        "blackLevel": 64,
+        "whiteLevel": 1023
+    },
+    "cameraControls": {}
+})V0G0N";
+
+    ASSERT_EQ(ref, json);
+
+    ImageMetadata parsed = parser::parseMetadata(json);
+    ASSERT_EQ(json, parser::serializeMetadata(parsed));
+}
+
+TEST(MetadataParserTest, TestSerializationExifTool) {
+    ExifMetadata exif;
+    exif.make = "Parser";
+    exif.model = "Test";
+    exif.orientation = 1;
+    exif.dngVersion = "1.4.0.0";
+    exif.exposureTime = ExifMetadata::Rational{1, 100};
+    exif.fNumber = ExifMetadata::Rational{56, 10};
+    exif.isoSpeedRatings = 100;
+    exif.shutterSpeedValue = ExifMetadata::SRational{7, 1};
+    exif.apertureValue = ExifMetadata::Rational{4, 1};
+    exif.focalLengthIn35mmFilm = 50;
+
+    std::string json = parser::serializeExifTool(exif, "image.jpg");
+    const char* ref = R"V0G0N([
+    {
+        "SourceFile": "image.jpg",
+        "IFD0:Make": "Parser",
+        "IFD0:Model": "Test",
+        "IFD0:Orientation": 1,
+        "IFD0:DNGVersion": "1 4 0 0",
+        "ExifIFD:ExposureTime": 0.01,
+        "ExifIFD:FNumber": 5.6,
+        "ExifIFD:ISO": 100,
+        "ExifIFD:ShutterSpeedValue": 0.0078125,
+        "ExifIFD:ApertureValue": 4,
+        "ExifIFD:FocalLengthIn35mmFormat": 50
+    }
+])V0G0N";
+
+    ASSERT_EQ(ref, json);
+}
+
+TEST(MetadataParserTest, TestSerializationExifToolDecimals) {
+    ExifMetadata exif;
+    exif.exposureTime = ExifMetadata::Rational{1, 60};
+    exif.fNumber = ExifMetadata::Rational{4, 1};
+    exif.shutterSpeedValue = ExifMetadata::SRational{591, 100};
+
+    std::string json = parser::serializeExifTool(exif, "image.jpg");
+    const char* ref = R"V0G0N([
+    {
+        "SourceFile": "image.jpg",
+        "ExifIFD:ExposureTime": 0.0166666666666667,
+        "ExifIFD:FNumber": 4,
+        "ExifIFD:ShutterSpeedValue": 0.0166307841008337
+    }
+])V0G0N";
+
+    ASSERT_EQ(ref, json);
+}
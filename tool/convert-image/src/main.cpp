@@ -14,6 +14,7 @@
 
 #include "io/ImageIO.h"
 #include "parser/MetadataParser.h"
+#include "util/File.h"
 #include "util/Version.h"
 
 #include <cxxopts.hpp>
@@ -87,6 +88,11 @@ static cxxopts::ParseResult handleArguments(int argc, char* argv[]) {
     }
 }
 
+static bool canEmbedIccProfile(const std::string& path) {
+    const std::string ext = file::extension(path);
+    return ext == "png" || ext == "tif" || ext == "tiff";
+}
+
 static void run(const std::string& inputPath,
                 const std::optional<std::string>& metadataPath,
                 const std::string& outputPath,
@@ -102,6 +108,13 @@ static void run(const std::string& inputPath,
     ImageWriter::Options writeOptions(metadata);
     writeOptions.jpegQuality = jpegQuality;
     writeOptions.tiffCompression = tiffCompression;
+    if (canEmbedIccProfile(outputPath)) {
+        try {
+            writeOptions.iccProfile = imageReader->readIccProfile();
+        } catch (const std::exception& e) {
+            LOG_S(WARNING) << "Cannot read ICC profile: " << e.what();
+        }
+    }
 
     std::unique_ptr<ImageWriter> imageWriter = io::makeWriter(outputPath, writeOptions);
     if (!imageWriter->acceptDescriptor(imageReader->layoutDescriptor())) {